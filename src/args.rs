@@ -3,7 +3,7 @@
 //! This module defines the command-line arguments for the Power Profiles
 //! Daemon CLI utility using the `clap` crate for argument parsing.
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
 /// Command-line interface for interacting with the Power Profiles Daemon
 #[derive(Parser, Debug)]
@@ -12,6 +12,19 @@ pub struct Args {
     /// Command to execute (defaults to list if not specified)
     #[command(subcommand)]
     pub command: Option<Commands>,
+
+    /// Output format for commands that print data
+    #[arg(long, global = true, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+/// Output format for commands that print data
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text (the default)
+    Text,
+    /// Machine-readable JSON, for scripts and status-bar modules
+    Json,
 }
 
 /// Available commands for the ppd utility
@@ -63,10 +76,25 @@ pub enum Commands {
     /// Query whether battery-aware behavior is enabled
     QueryBatteryAware,
 
+    /// Rotate to the next available power profile
+    Cycle {
+        /// Cycle backwards instead of forwards
+        #[arg(long)]
+        reverse: bool,
+
+        /// Restrict cycling to this comma-separated subset of profiles
+        #[arg(long, value_delimiter = ',')]
+        only: Option<Vec<String>>,
+    },
+
     /// Launch an application with a specific power profile
     Launch {
         /// Command and arguments to launch
-        arguments: String,
+        ///
+        /// Passed straight through to the child's argv without being split
+        /// again, so each argument reaches the child as the shell passed it.
+        #[arg(trailing_var_arg = true, required = true)]
+        arguments: Vec<String>,
 
         /// Profile to use for the application
         #[arg(short, long)]
@@ -25,6 +25,14 @@ pub enum PpdError {
     /// Feature is not yet implemented
     #[error("Unimplemented feature: {0}")]
     Unimplemented(String),
+
+    /// The daemon could not be reached on the bus
+    #[error("power-profiles-daemon is not running or not installed")]
+    DaemonUnavailable,
+
+    /// Error serializing command output
+    #[error("failed to serialize output: {0}")]
+    SerializeError(#[from] serde_json::Error),
 }
 
 /// A specialized Result type for ppd-rs operations
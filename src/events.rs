@@ -0,0 +1,45 @@
+//! Async event stream combining profile changes and forced releases
+//!
+//! Requires the `tokio` feature. Works on the zbus-generated async
+//! [`PpdProxy`](crate::PpdProxy), which exists alongside the blocking
+//! one. Long-lived watchers (status bars, GUI shells) can drive
+//! [`events`] from their own executor instead of dedicating a blocking
+//! thread to polling the bus.
+
+use futures::stream::{Stream, StreamExt};
+
+use crate::{PowerProfile, PpdProxy, Result};
+
+/// A typed event observed on the Power Profiles Daemon bus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PpdEvent {
+    /// The active profile changed, either by us or another client
+    ActiveProfileChanged(PowerProfile),
+    /// A profile hold was released, identified by its cookie
+    ProfileReleased(u32),
+}
+
+/// Subscribe to both `active_profile_changed` and `profile_released`
+///
+/// # Arguments
+///
+/// * `proxy` - The async PPD proxy object
+///
+/// # Returns
+///
+/// A stream of [`PpdEvent`]s that can be driven from any async executor
+pub async fn events(proxy: &PpdProxy<'_>) -> Result<impl Stream<Item = PpdEvent> + '_> {
+    let active_profile = proxy
+        .receive_active_profile_changed()
+        .await
+        .filter_map(|change| async move { change.get().await.ok() })
+        .map(PpdEvent::ActiveProfileChanged);
+
+    let released = proxy
+        .receive_profile_released()
+        .await?
+        .filter_map(|signal| async move { signal.body().deserialize::<u32>().ok() })
+        .map(PpdEvent::ProfileReleased);
+
+    Ok(futures::stream::select(active_profile, released))
+}
@@ -0,0 +1,161 @@
+//! Support for the `launch` subcommand
+//!
+//! Spawns a child process while holding a power profile for its duration,
+//! guaranteeing the hold is released even if the child is killed or `ppd`
+//! itself receives SIGINT/SIGTERM.
+
+use std::process::Command;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use signal_hook::consts::{SIGINT, SIGTERM};
+use signal_hook::iterator::Signals;
+
+use ppd::error::{PpdError, Result};
+use ppd::{PowerProfile, PpdProxyBlocking};
+
+/// Releases a profile hold on drop, so the hold can never outlive this guard
+struct HoldGuard {
+    proxy: PpdProxyBlocking<'static>,
+    cookie: u32,
+    released: Arc<AtomicBool>,
+}
+
+impl HoldGuard {
+    fn new(proxy: PpdProxyBlocking<'static>, cookie: u32) -> Self {
+        Self {
+            proxy,
+            cookie,
+            released: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// A flag that can be flipped from another thread to mark the hold as
+    /// already gone (e.g. released by the daemon), so `Drop` doesn't try to
+    /// release it a second time
+    fn released_flag(&self) -> Arc<AtomicBool> {
+        self.released.clone()
+    }
+}
+
+impl Drop for HoldGuard {
+    fn drop(&mut self) {
+        if claim_release(&self.released) {
+            let _ = self.proxy.release_profile(self.cookie);
+        }
+    }
+}
+
+/// Atomically claims the right to release a hold, returning `true` at most
+/// once for a given flag no matter how many times or from how many threads
+/// it's called
+fn claim_release(released: &AtomicBool) -> bool {
+    !released.swap(true, Ordering::SeqCst)
+}
+
+/// The conventional shell exit code for a process terminated by `signal`
+fn exit_code_for_signal(signal: i32) -> i32 {
+    128 + signal
+}
+
+/// Run `arguments` under a held power profile, releasing the hold once it exits
+///
+/// The hold is released when the child exits normally, when `ppd` itself is
+/// interrupted or terminated, and is recognised (rather than released twice)
+/// if the daemon releases the hold itself, signalled via `profile_released`.
+///
+/// # Arguments
+///
+/// * `proxy` - The PPD proxy object
+/// * `arguments` - The command and its arguments, already split by the shell
+///   (clap's `trailing_var_arg` hands these through untouched, so arguments
+///   containing spaces are preserved as single entries)
+/// * `profile` - Profile to hold while the command runs (defaults to `performance`)
+/// * `reason` - Reason recorded for the hold (defaults to the command line)
+/// * `appid` - Application ID recorded for the hold (defaults to the command name)
+///
+/// # Returns
+///
+/// The child process's exit code, suitable for propagating via `std::process::exit`
+pub fn launch(
+    proxy: &PpdProxyBlocking<'static>,
+    arguments: Vec<String>,
+    profile: Option<String>,
+    reason: Option<String>,
+    appid: Option<String>,
+) -> Result<i32> {
+    let mut argv = arguments.iter();
+    let program = argv
+        .next()
+        .ok_or_else(|| PpdError::InvalidConfig("no command given to launch".to_string()))?
+        .clone();
+    let args: Vec<&String> = argv.collect();
+
+    let profile = match profile {
+        Some(p) => PowerProfile::try_from(p.clone())
+            .map_err(|()| PpdError::InvalidConfig(format!("invalid profile: {p}")))?,
+        None => PowerProfile::Performance,
+    };
+    let reason = reason.unwrap_or_else(|| arguments.join(" "));
+    let appid = appid.unwrap_or_else(|| program.clone());
+
+    let cookie = proxy.hold_profile(profile, reason, appid)?;
+    let guard = HoldGuard::new(proxy.clone(), cookie);
+
+    // The daemon can release a hold on its own (e.g. a conflicting hold);
+    // watch for that so the guard doesn't try to release an already-gone cookie.
+    let released = guard.released_flag();
+    let released_signals = proxy.receive_profile_released()?;
+    std::thread::spawn(move || {
+        for signal in released_signals {
+            if let Ok(released_cookie) = signal.body().deserialize::<u32>() {
+                if released_cookie == cookie {
+                    claim_release(&released);
+                    break;
+                }
+            }
+        }
+    });
+
+    // SIGINT/SIGTERM bypass destructors by default, so release the hold
+    // explicitly before letting the signal terminate the process.
+    let signal_proxy = proxy.clone();
+    let signal_released = guard.released_flag();
+    let mut signals = Signals::new([SIGINT, SIGTERM])
+        .map_err(|e| PpdError::InvalidConfig(format!("failed to install signal handler: {e}")))?;
+    std::thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            if claim_release(&signal_released) {
+                let _ = signal_proxy.release_profile(cookie);
+            }
+            std::process::exit(exit_code_for_signal(signal));
+        }
+    });
+
+    let status = Command::new(&program)
+        .args(&args)
+        .status()
+        .map_err(|e| PpdError::InvalidConfig(format!("failed to launch {program}: {e}")))?;
+
+    drop(guard);
+    Ok(status.code().unwrap_or(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_release_only_once() {
+        let released = AtomicBool::new(false);
+        assert!(claim_release(&released));
+        assert!(!claim_release(&released));
+        assert!(!claim_release(&released));
+    }
+
+    #[test]
+    fn test_exit_code_for_signal() {
+        assert_eq!(exit_code_for_signal(SIGINT), 130);
+        assert_eq!(exit_code_for_signal(SIGTERM), 143);
+    }
+}
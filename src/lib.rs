@@ -37,6 +37,25 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Async usage
+//!
+//! The async [`PpdProxy`] is generated alongside the blocking one. With the
+//! `tokio` feature enabled, [`events`] is available too, for watching
+//! profile changes without a dedicated blocking thread:
+//!
+//! ```no_run
+//! # #[cfg(feature = "tokio")]
+//! # async fn run() -> ppd::Result<()> {
+//! use ppd::PpdProxy;
+//! use zbus::Connection;
+//!
+//! let connection = Connection::system().await?;
+//! let proxy = PpdProxy::new(&connection).await?;
+//! println!("Current profile: {}", proxy.active_profile().await?);
+//! # Ok(())
+//! # }
+//! ```
 
 use std::fmt::Display;
 
@@ -47,6 +66,7 @@ use zbus::{Result as ZbusResult, proxy};
 
 #[derive(Deserialize, Serialize, Type, Value, OwnedValue, Debug, PartialEq, Clone, Eq, Hash)]
 #[zvariant(signature = "s", rename_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
 pub enum PowerProfile {
     PowerSaver,
     Balanced,
@@ -272,10 +292,123 @@ pub trait Ppd {
     fn set_battery_aware(&self, value: bool) -> ZbusResult<()>;
 }
 
+/// Returns the D-Bus error name of a method error, if any
+fn error_name(err: &zbus::Error) -> Option<&str> {
+    match err {
+        zbus::Error::MethodError(name, _, _) => Some(name.as_str()),
+        _ => None,
+    }
+}
+
+/// Maps a low-level D-Bus error to [`PpdError::DaemonUnavailable`] when it
+/// indicates the daemon isn't running, leaving other errors untouched
+fn map_unavailable(err: zbus::Error) -> PpdError {
+    match error_name(&err) {
+        Some("org.freedesktop.DBus.Error.ServiceUnknown" | "org.freedesktop.DBus.Error.NameHasNoOwner") => {
+            PpdError::DaemonUnavailable
+        }
+        _ => PpdError::DBusError(err),
+    }
+}
+
+/// Legacy bus name used by power-profiles-daemon before it migrated to the
+/// `org.freedesktop.UPower.PowerProfiles` namespace
+const LEGACY_SERVICE: &str = "net.hadess.PowerProfiles";
+/// Legacy object path paired with [`LEGACY_SERVICE`]
+const LEGACY_PATH: &str = "/net/hadess/PowerProfiles";
+/// Legacy interface name paired with [`LEGACY_SERVICE`]
+const LEGACY_INTERFACE: &str = "net.hadess.PowerProfiles";
+
+/// Which bus namespace a [`PpdProxyBlocking`] ended up talking to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+    /// The canonical `org.freedesktop.UPower.PowerProfiles` namespace
+    Current,
+    /// The legacy `net.hadess.PowerProfiles` namespace used by older daemons
+    Legacy,
+}
+
+impl PpdProxyBlocking<'_> {
+    /// Probe whether power-profiles-daemon is actually running and reachable
+    ///
+    /// Constructing a proxy always succeeds even if nothing owns the bus
+    /// name, so this makes a real method call to tell "daemon not running"
+    /// apart from "not checked yet" before the first property read fails
+    /// with an opaque error.
+    pub fn is_available(&self) -> Result<bool> {
+        let inner = self.inner();
+        let props = zbus::blocking::fdo::PropertiesProxy::builder(inner.connection())
+            .destination(inner.destination())?
+            .path(inner.path())?
+            .build()?;
+
+        match props.get_all(inner.interface().clone()) {
+            Ok(_) => Ok(true),
+            Err(e) => match map_unavailable(e) {
+                PpdError::DaemonUnavailable => Ok(false),
+                other => Err(other),
+            },
+        }
+    }
+
+    /// Connect to the Power Profiles Daemon, failing fast with
+    /// [`PpdError::DaemonUnavailable`] if it isn't actually running
+    ///
+    /// # Arguments
+    ///
+    /// * `connection` - An established D-Bus system connection
+    pub fn connect_checked(connection: &zbus::blocking::Connection) -> Result<Self> {
+        let proxy = Self::new(connection)?;
+        if proxy.is_available()? {
+            Ok(proxy)
+        } else {
+            Err(PpdError::DaemonUnavailable)
+        }
+    }
+
+    /// Connect, falling back to the legacy `net.hadess.PowerProfiles` bus
+    /// name used by power-profiles-daemon versions before the move under
+    /// UPower
+    ///
+    /// Constructing a proxy never makes a D-Bus round trip by itself, so
+    /// `Self::new` succeeding says nothing about whether anything actually
+    /// owns the bus name. This probes each namespace with a real call via
+    /// [`Self::is_available`] before deciding whether to fall back.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection` - An established D-Bus system connection
+    ///
+    /// # Returns
+    ///
+    /// The proxy, along with which namespace it ended up connecting to
+    pub fn new_with_fallback(connection: &zbus::blocking::Connection) -> Result<(Self, Namespace)> {
+        let proxy = Self::new(connection)?;
+        if proxy.is_available()? {
+            return Ok((proxy, Namespace::Current));
+        }
+
+        let legacy = Self::builder(connection)
+            .destination(LEGACY_SERVICE)?
+            .path(LEGACY_PATH)?
+            .interface(LEGACY_INTERFACE)?
+            .build()?;
+        if legacy.is_available()? {
+            Ok((legacy, Namespace::Legacy))
+        } else {
+            Err(PpdError::DaemonUnavailable)
+        }
+    }
+}
+
 /// Error handling for the ppd library
 pub mod error;
 pub use error::{PpdError, Result};
 
+/// Async event stream support, gated behind the `tokio` feature
+#[cfg(feature = "tokio")]
+pub mod events;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,11 +9,13 @@
 use std::collections::HashSet;
 
 use clap::Parser;
-use ppd::PpdProxyBlocking;
+use ppd::{Namespace, PpdProxyBlocking};
+use serde::Serialize;
 
 mod args;
+mod launch;
 
-use args::Args;
+use args::{Args, OutputFormat};
 use ppd::error::{PpdError, Result};
 
 use zbus::blocking::Connection;
@@ -23,27 +25,45 @@ fn main() -> Result<()> {
     // Parse command-line arguments
     let cli = Args::parse();
 
-    // Connect to the system D-Bus and create a proxy to the Power Profiles Daemon
+    // Connect to the system D-Bus and create a proxy to the Power Profiles Daemon,
+    // falling back to the legacy bus name for older daemon versions
     let connection = Connection::system()?;
-    let proxy = PpdProxyBlocking::new(&connection)?;
+    let (proxy, namespace) = match PpdProxyBlocking::new_with_fallback(&connection) {
+        Ok(result) => result,
+        Err(PpdError::DaemonUnavailable) => {
+            eprintln!("{}", PpdError::DaemonUnavailable);
+            std::process::exit(1);
+        }
+        Err(e) => return Err(e),
+    };
+    if namespace == Namespace::Legacy {
+        eprintln!(
+            "note: power-profiles-daemon answered on the legacy net.hadess.PowerProfiles name ({})",
+            proxy.version().unwrap_or_default()
+        );
+    }
+
+    let output = cli.output;
 
     // Execute the appropriate command (or list if no command specified)
     match cli.command {
         Some(c) => match c {
-            args::Commands::Get => print_profile(&proxy)?,
-            args::Commands::List => list(&proxy)?,
-            args::Commands::ListHolds => {
-                Err(PpdError::Unimplemented("ListHolds command".to_string()))?
-            }
+            args::Commands::Get => print_profile(&proxy, output)?,
+            args::Commands::List => list(&proxy, output)?,
+            args::Commands::ListHolds => list_holds(&proxy, output)?,
             args::Commands::Set { profile } => set(&proxy, profile)?,
-            args::Commands::ListActions => list_actions(&proxy)?,
+            args::Commands::ListActions => list_actions(&proxy, output)?,
+            args::Commands::Cycle { reverse, only } => cycle(&proxy, reverse, only)?,
             args::Commands::Launch {
-                arguments: _,
-                profile: _,
-                reason: _,
-                appid: _,
-            } => Err(PpdError::Unimplemented("Launch command".to_string()))?,
-            args::Commands::QueryBatteryAware => query_battery_aware(&proxy)?,
+                arguments,
+                profile,
+                reason,
+                appid,
+            } => {
+                let code = launch::launch(&proxy, arguments, profile, reason, appid)?;
+                std::process::exit(code);
+            }
+            args::Commands::QueryBatteryAware => query_battery_aware(&proxy, output)?,
             args::Commands::ConfigureAction {
                 action: _,
                 enable: _,
@@ -56,7 +76,7 @@ fn main() -> Result<()> {
             }
             args::Commands::Watch => watch(&proxy)?,
         },
-        _ => list(&proxy)?,
+        _ => list(&proxy, output)?,
     };
     Ok(())
 }
@@ -66,12 +86,34 @@ fn main() -> Result<()> {
 /// # Arguments
 ///
 /// * `proxy` - The PPD proxy object
-fn print_profile(proxy: &PpdProxyBlocking) -> Result<()> {
+/// * `output` - Output format to print in
+fn print_profile(proxy: &PpdProxyBlocking, output: OutputFormat) -> Result<()> {
     let reply = proxy.active_profile()?;
-    println!("{reply}");
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string(&serde_json::json!({ "active": reply }))?),
+        OutputFormat::Text => println!("{reply}"),
+    }
     Ok(())
 }
 
+/// Structured `list` output for [`OutputFormat::Json`]
+#[derive(Serialize)]
+struct ProfileList {
+    active: ppd::PowerProfile,
+    degraded: String,
+    profiles: Vec<ProfileEntry>,
+}
+
+/// A single profile entry within [`ProfileList`]
+#[derive(Serialize)]
+struct ProfileEntry {
+    profile: ppd::PowerProfile,
+    active: bool,
+    driver: String,
+    platform_driver: Option<String>,
+    cpu_driver: Option<String>,
+}
+
 /// List all available power profiles and their properties
 ///
 /// This function displays all available profiles with their respective
@@ -81,7 +123,9 @@ fn print_profile(proxy: &PpdProxyBlocking) -> Result<()> {
 /// # Arguments
 ///
 /// * `proxy` - The PPD proxy object
-fn list(proxy: &PpdProxyBlocking) -> Result<()> {
+/// * `output` - Output format to print in; JSON output flags the active
+///   profile with an `active` field instead of an asterisk
+fn list(proxy: &PpdProxyBlocking, output: OutputFormat) -> Result<()> {
     let current = proxy.active_profile()?;
     let profiles = proxy.profiles()?;
     let degraded = proxy
@@ -90,29 +134,51 @@ fn list(proxy: &PpdProxyBlocking) -> Result<()> {
         .unwrap_or(&String::from("no"))
         .to_string();
 
-    let mut profiles_iter = profiles.into_iter().rev().peekable();
+    match output {
+        OutputFormat::Json => {
+            let entries = profiles
+                .into_iter()
+                .map(|profile| ProfileEntry {
+                    active: profile.profile == current,
+                    profile: profile.profile,
+                    driver: profile.driver,
+                    platform_driver: profile.platform_driver,
+                    cpu_driver: profile.cpu_driver,
+                })
+                .collect();
+            let list = ProfileList {
+                active: current,
+                degraded,
+                profiles: entries,
+            };
+            println!("{}", serde_json::to_string(&list)?);
+        }
+        OutputFormat::Text => {
+            let mut profiles_iter = profiles.into_iter().rev().peekable();
 
-    while let Some(profile) = profiles_iter.next() {
-        let degraded_string = if profile.profile == "performance" {
-            Some(degraded.clone())
-        } else {
-            None
-        };
+            while let Some(profile) = profiles_iter.next() {
+                let degraded_string = if profile.profile == "performance" {
+                    Some(degraded.clone())
+                } else {
+                    None
+                };
 
-        let current_marker = if current == profile.profile { "*" } else { " " };
-        println!("{} {}:", current_marker, profile.profile);
-        if let Some(s) = profile.cpu_driver.clone() {
-            println!("    CpuDriver:\t{}", s);
-        }
-        if let Some(s) = profile.platform_driver.clone() {
-            println!("    PlatformDriver:\t{}", s);
-        }
-        if let Some(s) = degraded_string {
-            println!("    Degraded:  {}", s);
-        }
+                let current_marker = if current == profile.profile { "*" } else { " " };
+                println!("{} {}:", current_marker, profile.profile);
+                if let Some(s) = profile.cpu_driver.clone() {
+                    println!("    CpuDriver:\t{}", s);
+                }
+                if let Some(s) = profile.platform_driver.clone() {
+                    println!("    PlatformDriver:\t{}", s);
+                }
+                if let Some(s) = degraded_string {
+                    println!("    Degraded:  {}", s);
+                }
 
-        if profiles_iter.peek().is_some() {
-            println!();
+                if profiles_iter.peek().is_some() {
+                    println!();
+                }
+            }
         }
     }
     Ok(())
@@ -152,27 +218,211 @@ fn set(proxy: &PpdProxyBlocking, profile: String) -> Result<()> {
     }
 }
 
+/// Rotate to the next available power profile
+///
+/// Walks the ordered list of profiles as reported by the daemon rather than
+/// a hardcoded order, so it respects whatever the daemon considers "next".
+///
+/// # Arguments
+///
+/// * `proxy` - The PPD proxy object
+/// * `reverse` - Cycle backwards instead of forwards
+/// * `only` - Restrict cycling to this subset of profile names
+///
+/// # Returns
+///
+/// An error if `only` excludes every available profile
+fn cycle(proxy: &PpdProxyBlocking, reverse: bool, only: Option<Vec<String>>) -> Result<()> {
+    let mut names: Vec<_> = proxy.profiles()?.into_iter().map(|p| p.profile).collect();
+
+    if let Some(only) = only {
+        let only: HashSet<String> = only.into_iter().collect();
+        names.retain(|p| only.contains(&p.to_string()));
+    }
+
+    if names.is_empty() {
+        return Err(PpdError::InvalidConfig(
+            "no profiles available to cycle through".to_string(),
+        ));
+    }
+
+    let current = proxy.active_profile()?;
+    let next = next_in_cycle(&names, &current, reverse);
+
+    proxy.set_active_profile(next.clone())?;
+    println!("{next}");
+    Ok(())
+}
+
+/// Compute the next profile to switch to when cycling
+///
+/// `names` must be non-empty; it's assumed to already be filtered down to
+/// whatever subset the caller wants to cycle through (e.g. via `--only`).
+/// If `current` isn't in `names` (e.g. it was excluded by `--only`), cycling
+/// starts from the first entry (the last one when `reverse` is set).
+fn next_in_cycle(names: &[ppd::PowerProfile], current: &ppd::PowerProfile, reverse: bool) -> ppd::PowerProfile {
+    let mut ordered = names.to_vec();
+    if reverse {
+        ordered.reverse();
+    }
+    let next_index = match ordered.iter().position(|p| p == current) {
+        Some(index) => (index + 1) % ordered.len(),
+        None => 0,
+    };
+    ordered[next_index].clone()
+}
+
+#[cfg(test)]
+mod cycle_tests {
+    use super::next_in_cycle;
+    use ppd::PowerProfile;
+
+    fn all() -> Vec<PowerProfile> {
+        vec![
+            PowerProfile::PowerSaver,
+            PowerProfile::Balanced,
+            PowerProfile::Performance,
+        ]
+    }
+
+    #[test]
+    fn test_advances_to_next_profile() {
+        let next = next_in_cycle(&all(), &PowerProfile::PowerSaver, false);
+        assert_eq!(next, PowerProfile::Balanced);
+    }
+
+    #[test]
+    fn test_wraps_around_forward() {
+        let next = next_in_cycle(&all(), &PowerProfile::Performance, false);
+        assert_eq!(next, PowerProfile::PowerSaver);
+    }
+
+    #[test]
+    fn test_wraps_around_reverse() {
+        let next = next_in_cycle(&all(), &PowerProfile::PowerSaver, true);
+        assert_eq!(next, PowerProfile::Performance);
+    }
+
+    #[test]
+    fn test_reverse_steps_backward() {
+        let next = next_in_cycle(&all(), &PowerProfile::Performance, true);
+        assert_eq!(next, PowerProfile::Balanced);
+    }
+
+    #[test]
+    fn test_current_excluded_starts_from_first() {
+        let only = vec![PowerProfile::PowerSaver, PowerProfile::Balanced];
+        let next = next_in_cycle(&only, &PowerProfile::Performance, false);
+        assert_eq!(next, PowerProfile::PowerSaver);
+    }
+
+    #[test]
+    fn test_single_profile_is_a_no_op() {
+        let only = vec![PowerProfile::Balanced];
+        let next = next_in_cycle(&only, &PowerProfile::Balanced, false);
+        assert_eq!(next, PowerProfile::Balanced);
+    }
+}
+
 /// Query whether battery-aware behavior is enabled
 ///
 /// # Arguments
 ///
 /// * `proxy` - The PPD proxy object
-fn query_battery_aware(proxy: &PpdProxyBlocking) -> Result<()> {
+/// * `output` - Output format to print in
+fn query_battery_aware(proxy: &PpdProxyBlocking, output: OutputFormat) -> Result<()> {
     let ba = proxy.battery_aware()?;
-    println!("Dynamic changes from charger and battery events: {}", ba);
+    match output {
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string(&serde_json::json!({ "battery_aware": ba }))?
+        ),
+        OutputFormat::Text => println!("Dynamic changes from charger and battery events: {}", ba),
+    }
     Ok(())
 }
 
+/// Structured `list-actions` entry for [`OutputFormat::Json`]
+///
+/// Mirrors [`ppd::Action`] but with `snake_case` field names, so JSON output
+/// is consistent across every subcommand rather than following the `Action`
+/// type's `PascalCase` D-Bus wire format.
+#[derive(Serialize)]
+struct ActionEntry {
+    name: String,
+    description: String,
+    enabled: bool,
+}
+
 /// List all available power-related actions
 ///
 /// # Arguments
 ///
 /// * `proxy` - The PPD proxy object
-fn list_actions(proxy: &PpdProxyBlocking) -> Result<()> {
-    for action in proxy.actions_info()? {
-        println!("Name: {}", action.name);
-        println!("Description: {}", action.description);
-        println!("Enabled: {}", action.enabled);
+/// * `output` - Output format to print in
+fn list_actions(proxy: &PpdProxyBlocking, output: OutputFormat) -> Result<()> {
+    let actions = proxy.actions_info()?;
+    match output {
+        OutputFormat::Json => {
+            let entries: Vec<_> = actions
+                .into_iter()
+                .map(|action| ActionEntry {
+                    name: action.name,
+                    description: action.description,
+                    enabled: action.enabled,
+                })
+                .collect();
+            println!("{}", serde_json::to_string(&entries)?);
+        }
+        OutputFormat::Text => {
+            for action in actions {
+                println!("Name: {}", action.name);
+                println!("Description: {}", action.description);
+                println!("Enabled: {}", action.enabled);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Structured `list-holds` entry for [`OutputFormat::Json`]
+///
+/// Mirrors [`ppd::ActiveHold`] but with `snake_case` field names, for the
+/// same reason as [`ActionEntry`].
+#[derive(Serialize)]
+struct HoldEntry {
+    application_id: String,
+    reason: String,
+    profile: ppd::PowerProfile,
+}
+
+/// List all active profile holds
+///
+/// # Arguments
+///
+/// * `proxy` - The PPD proxy object
+/// * `output` - Output format to print in
+fn list_holds(proxy: &PpdProxyBlocking, output: OutputFormat) -> Result<()> {
+    let holds = proxy.active_profile_holds()?;
+    match output {
+        OutputFormat::Json => {
+            let entries: Vec<_> = holds
+                .into_iter()
+                .map(|hold| HoldEntry {
+                    application_id: hold.application_id,
+                    reason: hold.reason,
+                    profile: hold.profile,
+                })
+                .collect();
+            println!("{}", serde_json::to_string(&entries)?);
+        }
+        OutputFormat::Text => {
+            for hold in holds {
+                println!("ApplicationId: {}", hold.application_id);
+                println!("Reason: {}", hold.reason);
+                println!("Profile: {}", hold.profile);
+            }
+        }
     }
     Ok(())
 }